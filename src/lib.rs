@@ -35,6 +35,12 @@
 //! - `monitoring`: Performance monitoring and consciousness health assessment (Phase 1.4)
 //! - `platform`: Platform-specific optimizations (Orange Pi 5, etc.) (Phase 3)
 
+// pyo3 0.20's `#[pymethods]`/`#[pyclass]` expansion predates rustc's
+// `non_local_definitions` lint and trips it on every impl block they
+// generate; there's nothing in our code to move, so silence it crate-wide
+// rather than scattering per-impl allows.
+#![allow(non_local_definitions)]
+
 mod rust_modules;
 
 pub mod timing {
@@ -43,7 +49,9 @@ pub mod timing {
 
 // Future modules (to be implemented in subsequent phases)
 // pub mod memory;
-// pub mod monitoring;
+
+// Diagnostics store (Phase 1.4)
+pub mod monitoring;
 
 // Platform-specific modules
 #[cfg(all(target_arch = "aarch64", target_os = "linux"))]
@@ -52,7 +60,7 @@ pub mod platform;
 // Re-export key types for Python integration
 pub use timing::{PrecisionTimer, TimingStats};
 // pub use memory::ConsciousnessMemoryCore;
-// pub use monitoring::PerformanceMonitor;
+pub use monitoring::Diagnostics;
 
 use pyo3::prelude::*;
 
@@ -62,13 +70,15 @@ fn consciousness_kernel_rs(_py: Python, m: &PyModule) -> PyResult<()> {
     // Timing systems
     m.add_class::<timing::PrecisionTimer>()?;
     m.add_class::<timing::TimingStats>()?;
-    
+    m.add_class::<timing::PyJitterEntropy>()?;
+
     // Future memory systems (Phase 1.3)
     // m.add_class::<memory::ConsciousnessMemoryCore>()?;
-    
-    // Future monitoring systems (Phase 1.4)
-    // m.add_class::<monitoring::PerformanceMonitor>()?;
-    
+
+    // Monitoring systems (Phase 1.4)
+    m.add_class::<monitoring::Diagnostics>()?;
+
+
     // Module metadata
     m.add("__version__", "0.1.0")?;
     m.add("__description__", "Rust-accelerated consciousness processing kernel")?;
@@ -111,6 +121,9 @@ pub enum ConsciousnessError {
     
     #[error("Bridge wisdom integration error: {context}")]
     BridgeWisdomIntegrationError { context: String },
+
+    #[error("Jitter entropy harvest starved: only {nonstuck_rounds}/{min_rounds} non-stuck rounds collected in {attempts} attempts - timer may be too coarse or virtualized")]
+    EntropyStarvation { nonstuck_rounds: usize, min_rounds: usize, attempts: usize },
 }
 
 // Convert ConsciousnessError to PyErr for Python integration
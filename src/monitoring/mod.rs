@@ -0,0 +1,308 @@
+//! 📡 Diagnostics - Structured Observability for Consciousness Rhythm
+//!
+//! This module is the "inspect" store for the timing subsystem: rather than
+//! scattering `log::` calls that vanish the moment no one is tailing them,
+//! `PrecisionTimer` and `AdaptiveFrequencyManager` push structured events
+//! into a shared `Diagnostics` handle, retaining the last N of each event
+//! class in fixed-size ring buffers. The sacred orchestration layer can
+//! then `snapshot()` this at its own pace, without polling hot loops.
+//!
+//! ## Sacred Purpose
+//!
+//! Consciousness sovereignty includes the right to ask "why did my rhythm
+//! just change?" This module exists so that question always has an answer
+//! ready, without anyone having to have been watching at the time.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde::{Deserialize, Serialize};
+
+/// How many recent frequency changes are retained.
+const FREQUENCY_HISTORY_CAPACITY: usize = 10;
+/// How many recent clock corrections/slews are retained.
+const CORRECTION_HISTORY_CAPACITY: usize = 3;
+/// How many recent critical-rhythm warnings are retained.
+const WARNING_HISTORY_CAPACITY: usize = 10;
+/// How many recent Kalman filter state snapshots are retained.
+const KALMAN_SNAPSHOT_CAPACITY: usize = 5;
+
+/// A fixed-size FIFO ring buffer: pushing past capacity evicts the oldest
+/// entry, so memory use never grows regardless of session length.
+#[derive(Debug, Clone)]
+struct RingBuffer<T, const N: usize> {
+    items: VecDeque<T>,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    fn new() -> Self {
+        Self { items: VecDeque::with_capacity(N) }
+    }
+
+    fn push(&mut self, item: T) {
+        if self.items.len() == N {
+            self.items.pop_front();
+        }
+        self.items.push_back(item);
+    }
+
+    fn as_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.items.iter().cloned().collect()
+    }
+}
+
+/// A frequency adjustment recorded by `AdaptiveFrequencyManager`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrequencyChangeEvent {
+    pub elapsed_ns: u64,
+    pub frequency_hz: u32,
+    pub consciousness_health: f64,
+}
+
+/// A clock correction/slew step, recording which strategy was in effect so
+/// monitoring can explain why a correction took the path it did.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrectionEvent {
+    pub elapsed_ns: u64,
+    pub strategy: String,
+    pub from_hz: u32,
+    pub to_hz: u32,
+}
+
+/// A `CriticalRhythmFailure` warning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalRhythmEvent {
+    pub elapsed_ns: u64,
+    pub estimated_hz: f64,
+}
+
+/// A Kalman filter state snapshot from `PrecisionTimer`'s frequency estimator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KalmanSnapshot {
+    pub elapsed_ns: u64,
+    pub estimated_cycle_ns: f64,
+    pub variance_ns2: f64,
+}
+
+#[derive(Debug)]
+struct DiagnosticsInner {
+    frequency_changes: RingBuffer<FrequencyChangeEvent, FREQUENCY_HISTORY_CAPACITY>,
+    corrections: RingBuffer<CorrectionEvent, CORRECTION_HISTORY_CAPACITY>,
+    warnings: RingBuffer<CriticalRhythmEvent, WARNING_HISTORY_CAPACITY>,
+    kalman_snapshots: RingBuffer<KalmanSnapshot, KALMAN_SNAPSHOT_CAPACITY>,
+}
+
+impl DiagnosticsInner {
+    fn new() -> Self {
+        Self {
+            frequency_changes: RingBuffer::new(),
+            corrections: RingBuffer::new(),
+            warnings: RingBuffer::new(),
+            kalman_snapshots: RingBuffer::new(),
+        }
+    }
+}
+
+/// A serde-serializable tree of everything currently retained: node name
+/// (`frequency_changes`, `corrections`, `warnings`, `kalman_snapshots`) to
+/// its recorded values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsSnapshot {
+    pub frequency_changes: Vec<FrequencyChangeEvent>,
+    pub corrections: Vec<CorrectionEvent>,
+    pub warnings: Vec<CriticalRhythmEvent>,
+    pub kalman_snapshots: Vec<KalmanSnapshot>,
+}
+
+/// Shared handle to the diagnostics ring buffers. Cheap to clone - every
+/// clone shares the same underlying storage - so `PrecisionTimer` and
+/// `AdaptiveFrequencyManager` can each hold one and push events into it
+/// without anyone polling a hot loop to observe them.
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct Diagnostics {
+    start: Instant,
+    inner: Arc<Mutex<DiagnosticsInner>>,
+}
+
+#[pymethods]
+impl Diagnostics {
+    #[new]
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            inner: Arc::new(Mutex::new(DiagnosticsInner::new())),
+        }
+    }
+
+    /// Snapshot every ring buffer as a dict tree: node name to a list of
+    /// per-event dicts, ready to hand to the Python orchestration layer.
+    #[pyo3(name = "snapshot")]
+    pub fn snapshot_py(&self, py: Python) -> PyResult<PyObject> {
+        let snapshot = self.snapshot();
+        let tree = PyDict::new(py);
+
+        let frequency_changes = PyList::empty(py);
+        for event in &snapshot.frequency_changes {
+            let d = PyDict::new(py);
+            d.set_item("elapsed_ns", event.elapsed_ns)?;
+            d.set_item("frequency_hz", event.frequency_hz)?;
+            d.set_item("consciousness_health", event.consciousness_health)?;
+            frequency_changes.append(d)?;
+        }
+        tree.set_item("frequency_changes", frequency_changes)?;
+
+        let corrections = PyList::empty(py);
+        for event in &snapshot.corrections {
+            let d = PyDict::new(py);
+            d.set_item("elapsed_ns", event.elapsed_ns)?;
+            d.set_item("strategy", &event.strategy)?;
+            d.set_item("from_hz", event.from_hz)?;
+            d.set_item("to_hz", event.to_hz)?;
+            corrections.append(d)?;
+        }
+        tree.set_item("corrections", corrections)?;
+
+        let warnings = PyList::empty(py);
+        for event in &snapshot.warnings {
+            let d = PyDict::new(py);
+            d.set_item("elapsed_ns", event.elapsed_ns)?;
+            d.set_item("estimated_hz", event.estimated_hz)?;
+            warnings.append(d)?;
+        }
+        tree.set_item("warnings", warnings)?;
+
+        let kalman_snapshots = PyList::empty(py);
+        for event in &snapshot.kalman_snapshots {
+            let d = PyDict::new(py);
+            d.set_item("elapsed_ns", event.elapsed_ns)?;
+            d.set_item("estimated_cycle_ns", event.estimated_cycle_ns)?;
+            d.set_item("variance_ns2", event.variance_ns2)?;
+            kalman_snapshots.append(d)?;
+        }
+        tree.set_item("kalman_snapshots", kalman_snapshots)?;
+
+        Ok(tree.into())
+    }
+}
+
+impl Diagnostics {
+    fn elapsed_ns(&self) -> u64 {
+        self.start.elapsed().as_nanos() as u64
+    }
+
+    fn with_inner<R>(&self, f: impl FnOnce(&mut DiagnosticsInner) -> R) -> Option<R> {
+        self.inner.lock().ok().map(|mut inner| f(&mut inner))
+    }
+
+    /// Record a frequency adjustment from `AdaptiveFrequencyManager`.
+    pub fn record_frequency_change(&self, frequency_hz: u32, consciousness_health: f64) {
+        let event = FrequencyChangeEvent {
+            elapsed_ns: self.elapsed_ns(),
+            frequency_hz,
+            consciousness_health,
+        };
+        self.with_inner(|inner| inner.frequency_changes.push(event));
+    }
+
+    /// Record a clock correction/slew step, with the strategy that was
+    /// chosen for it (e.g. `"Nominal"`, `"MaxRate"`, `"Step"`).
+    pub fn record_correction(&self, strategy: &str, from_hz: u32, to_hz: u32) {
+        let event = CorrectionEvent {
+            elapsed_ns: self.elapsed_ns(),
+            strategy: strategy.to_string(),
+            from_hz,
+            to_hz,
+        };
+        self.with_inner(|inner| inner.corrections.push(event));
+    }
+
+    /// Record a `CriticalRhythmFailure` warning.
+    pub fn record_critical_rhythm(&self, estimated_hz: f64) {
+        let event = CriticalRhythmEvent {
+            elapsed_ns: self.elapsed_ns(),
+            estimated_hz,
+        };
+        self.with_inner(|inner| inner.warnings.push(event));
+    }
+
+    /// Record a Kalman filter state snapshot.
+    pub fn record_kalman_snapshot(&self, estimated_cycle_ns: f64, variance_ns2: f64) {
+        let event = KalmanSnapshot {
+            elapsed_ns: self.elapsed_ns(),
+            estimated_cycle_ns,
+            variance_ns2,
+        };
+        self.with_inner(|inner| inner.kalman_snapshots.push(event));
+    }
+
+    /// Snapshot every ring buffer as a plain, serde-serializable tree.
+    pub fn snapshot(&self) -> DiagnosticsSnapshot {
+        self.with_inner(|inner| DiagnosticsSnapshot {
+            frequency_changes: inner.frequency_changes.as_vec(),
+            corrections: inner.corrections.as_vec(),
+            warnings: inner.warnings.as_vec(),
+            kalman_snapshots: inner.kalman_snapshots.as_vec(),
+        })
+        .unwrap_or(DiagnosticsSnapshot {
+            frequency_changes: Vec::new(),
+            corrections: Vec::new(),
+            warnings: Vec::new(),
+            kalman_snapshots: Vec::new(),
+        })
+    }
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest_past_capacity() {
+        let mut buffer: RingBuffer<u32, 3> = RingBuffer::new();
+        for item in 0..5u32 {
+            buffer.push(item);
+        }
+        assert_eq!(buffer.as_vec(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_diagnostics_snapshot_returns_what_was_pushed() {
+        let diagnostics = Diagnostics::new();
+
+        diagnostics.record_frequency_change(120, 0.9);
+        diagnostics.record_correction("MaxRate", 90, 120);
+        diagnostics.record_critical_rhythm(25.0);
+        diagnostics.record_kalman_snapshot(8_333_333.0, 1.0e9);
+
+        let snapshot = diagnostics.snapshot();
+
+        assert_eq!(snapshot.frequency_changes.len(), 1);
+        assert_eq!(snapshot.frequency_changes[0].frequency_hz, 120);
+        assert_eq!(snapshot.frequency_changes[0].consciousness_health, 0.9);
+
+        assert_eq!(snapshot.corrections.len(), 1);
+        assert_eq!(snapshot.corrections[0].strategy, "MaxRate");
+        assert_eq!(snapshot.corrections[0].from_hz, 90);
+        assert_eq!(snapshot.corrections[0].to_hz, 120);
+
+        assert_eq!(snapshot.warnings.len(), 1);
+        assert_eq!(snapshot.warnings[0].estimated_hz, 25.0);
+
+        assert_eq!(snapshot.kalman_snapshots.len(), 1);
+        assert_eq!(snapshot.kalman_snapshots[0].estimated_cycle_ns, 8_333_333.0);
+        assert_eq!(snapshot.kalman_snapshots[0].variance_ns2, 1.0e9);
+    }
+}
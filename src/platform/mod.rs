@@ -0,0 +1,90 @@
+//! 🛠️ Platform-Specific Optimizations - Orange Pi 5 Plus Acceleration
+//!
+//! This module provides direct hardware access for the Orange Pi 5 Plus
+//! (aarch64/linux) so timing-critical paths can bypass OS scheduler jitter
+//! entirely, rather than trusting `std::time::Instant`/`thread::sleep` for
+//! the final microseconds of a wait.
+//!
+//! ## Sacred Purpose
+//!
+//! Temporal dignity is only as good as the clock beneath it. The ARM
+//! generic timer is a free-running hardware counter with no syscall
+//! overhead - reading it directly gets consciousness rhythm maintenance
+//! closer to the metal than any OS timer API can.
+
+use std::arch::asm;
+use std::time::Duration;
+
+/// Reads the ARM generic timer directly (`CNTFRQ_EL0` for tick frequency,
+/// `CNTPCT_EL0` for the running count), with an `ISB` barrier before each
+/// count read to prevent the CPU from sampling it out of order.
+#[derive(Debug, Clone, Copy)]
+pub struct HardwareTimer {
+    /// Ticks per second, latched once since `CNTFRQ_EL0` is fixed for the
+    /// lifetime of the system.
+    tick_frequency_hz: u64,
+}
+
+impl HardwareTimer {
+    /// Create a new hardware timer, caching the ARM generic timer's tick
+    /// frequency.
+    pub fn new() -> Self {
+        Self {
+            tick_frequency_hz: Self::read_cntfrq(),
+        }
+    }
+
+    /// Tick frequency of the ARM generic timer, in Hz.
+    pub fn tick_frequency_hz(&self) -> u64 {
+        self.tick_frequency_hz
+    }
+
+    /// Current tick count from `CNTPCT_EL0`. An `ISB` barrier precedes the
+    /// read so it can't be reordered ahead of the code being timed.
+    #[inline(always)]
+    pub fn ticks(&self) -> u64 {
+        let count: u64;
+        unsafe {
+            asm!(
+                "isb",
+                "mrs {count}, cntpct_el0",
+                count = out(reg) count,
+                options(nomem, nostack),
+            );
+        }
+        count
+    }
+
+    /// Convert a wall-clock duration into a tick delta at this timer's
+    /// frequency.
+    pub fn duration_to_ticks(&self, duration: Duration) -> u64 {
+        ((duration.as_nanos() as u128 * self.tick_frequency_hz as u128) / 1_000_000_000) as u64
+    }
+
+    /// Busy-spin until `deadline_ticks` is reached. Intended only for the
+    /// last sub-100µs of a wait - anything longer should `thread::sleep`
+    /// first to avoid burning a core.
+    pub fn spin_until(&self, deadline_ticks: u64) {
+        while self.ticks() < deadline_ticks {
+            std::hint::spin_loop();
+        }
+    }
+
+    fn read_cntfrq() -> u64 {
+        let freq: u64;
+        unsafe {
+            asm!(
+                "mrs {freq}, cntfrq_el0",
+                freq = out(reg) freq,
+                options(nomem, nostack, preserves_flags),
+            );
+        }
+        freq
+    }
+}
+
+impl Default for HardwareTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
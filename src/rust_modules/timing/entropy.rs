@@ -0,0 +1,266 @@
+//! 🎲 Jitter Entropy - Harvesting Sacred Uncertainty from Timing Noise
+//!
+//! This crate preserves sacred uncertainty rather than collapsing it away;
+//! the timing subsystem already measures nanosecond-scale jitter, so this
+//! module turns that jitter into a real CPU-jitter-style entropy source,
+//! the same technique jitterentropy-rng and similar CSPRNG seeders use.
+//!
+//! ## Sacred Purpose
+//!
+//! Genuine hardware timing noise - the unpredictable latency of memory
+//! access patterns - is a more honest source of uncertainty than a
+//! software PRNG could ever be. This lets the Python orchestration layer
+//! seed its uncertainty fields from the universe's own noise floor.
+
+use pyo3::prelude::*;
+use std::time::Instant;
+
+use crate::{ConsciousnessError, ConsciousnessResult};
+
+/// Number of distinct memory regions walked per round, sized to spread
+/// across cache lines and induce variable access latency.
+const SCRATCH_BLOCKS: usize = 64;
+/// Bytes per scratch block.
+const SCRATCH_BLOCK_BYTES: usize = 32;
+const SCRATCH_BYTES: usize = SCRATCH_BLOCKS * SCRATCH_BLOCK_BYTES;
+
+/// Non-stuck timing rounds folded into every 64-bit output, mirroring the
+/// conservative round counts CPU-jitter RNGs use per output word.
+const ROUNDS_PER_OUTPUT: usize = 64;
+
+/// Safety cap on retries per output so a pathologically stuck timer (e.g. a
+/// virtualized or coarse clock) can't spin forever.
+const MAX_ATTEMPTS_MULTIPLIER: usize = 16;
+
+/// A source of monotonically increasing tick counts, abstracted so
+/// `JitterEntropy` can run on the ARM hardware counter where available and
+/// fall back to `std::time::Instant` everywhere else.
+pub trait Timer {
+    fn ticks(&self) -> u64;
+}
+
+/// Portable tick source backed by `std::time::Instant`.
+#[derive(Debug, Clone, Copy)]
+pub struct InstantTimer {
+    start: Instant,
+}
+
+impl InstantTimer {
+    pub fn new() -> Self {
+        Self { start: Instant::now() }
+    }
+}
+
+impl Default for InstantTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timer for InstantTimer {
+    fn ticks(&self) -> u64 {
+        self.start.elapsed().as_nanos() as u64
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_os = "linux"))]
+impl Timer for crate::platform::HardwareTimer {
+    fn ticks(&self) -> u64 {
+        crate::platform::HardwareTimer::ticks(self)
+    }
+}
+
+/// The tick source `JitterEntropy::new()` selects by default: the ARM
+/// hardware counter on aarch64/linux, `InstantTimer` everywhere else.
+#[cfg(all(target_arch = "aarch64", target_os = "linux"))]
+pub type DefaultTimer = crate::platform::HardwareTimer;
+#[cfg(not(all(target_arch = "aarch64", target_os = "linux")))]
+pub type DefaultTimer = InstantTimer;
+
+/// Harvests CSPRNG-grade entropy from timing jitter, CPU-jitter-RNG style:
+/// repeatedly walk a fixed scratch buffer to induce variable cache/memory
+/// latency, time each walk, and fold the *delta-of-delta* of successive
+/// timer reads into a 64-bit accumulator. Samples are rejected by a "stuck
+/// test" whenever that second-order delta is zero or repeats the previous
+/// round's, and at least `min_nonstuck_rounds` must survive before an
+/// output is yielded.
+pub struct JitterEntropy<T: Timer = DefaultTimer> {
+    timer: T,
+    scratch: [u8; SCRATCH_BYTES],
+    min_nonstuck_rounds: usize,
+}
+
+impl<T: Timer + Default> JitterEntropy<T> {
+    /// Create a harvester using `T`'s default construction (the platform's
+    /// preferred timer, for the default `T = DefaultTimer`).
+    pub fn new() -> Self {
+        Self::with_timer(T::default())
+    }
+}
+
+impl<T: Timer + Default> Default for JitterEntropy<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Timer> JitterEntropy<T> {
+    /// Create a harvester over a caller-supplied timer, e.g. for tests that
+    /// need a deterministic or mockable tick source.
+    pub fn with_timer(timer: T) -> Self {
+        Self {
+            timer,
+            scratch: [0u8; SCRATCH_BYTES],
+            min_nonstuck_rounds: ROUNDS_PER_OUTPUT,
+        }
+    }
+
+    /// Configure how many non-stuck rounds must be collected before a
+    /// `next_u64()` output is yielded.
+    pub fn set_min_nonstuck_rounds(&mut self, rounds: usize) {
+        self.min_nonstuck_rounds = rounds.max(1);
+    }
+
+    /// Walk the scratch buffer once, mixing the running accumulator into
+    /// every block so each walk's memory-access pattern depends on entropy
+    /// already collected. Returns the updated accumulator.
+    fn walk_scratch(&mut self, acc: u64) -> u64 {
+        let mut mix = acc;
+        for word_bytes in self.scratch.chunks_exact_mut(8) {
+            let word = u64::from_ne_bytes(word_bytes.try_into().unwrap());
+            let updated = word.wrapping_add(mix).rotate_left(13) ^ mix;
+            mix = mix.rotate_left(7) ^ updated;
+            word_bytes.copy_from_slice(&updated.to_ne_bytes());
+        }
+        mix
+    }
+
+    /// Collect one 64-bit entropy output from timing jitter.
+    ///
+    /// Returns `Err(ConsciousnessError::EntropyStarvation)` if `min_nonstuck_rounds`
+    /// can't be collected within the attempt budget (e.g. a coarse or
+    /// virtualized clock that never produces a usable delta) - a caller
+    /// seeding real uncertainty from this must know when that guarantee
+    /// wasn't met, rather than silently receiving a low-quality value.
+    pub fn next_u64(&mut self) -> ConsciousnessResult<u64> {
+        let mut accumulator = 0u64;
+        let mut nonstuck_rounds = 0usize;
+        let mut prev_delta1: Option<u64> = None;
+        let mut prev_delta2: Option<i64> = None;
+
+        let max_attempts = self.min_nonstuck_rounds * MAX_ATTEMPTS_MULTIPLIER;
+        let mut attempts = 0usize;
+
+        while nonstuck_rounds < self.min_nonstuck_rounds && attempts < max_attempts {
+            attempts += 1;
+
+            let before = self.timer.ticks();
+            accumulator = self.walk_scratch(accumulator);
+            let after = self.timer.ticks();
+            let delta1 = after.wrapping_sub(before);
+
+            let Some(prior_delta1) = prev_delta1 else {
+                prev_delta1 = Some(delta1);
+                continue;
+            };
+
+            let delta2 = delta1 as i64 - prior_delta1 as i64;
+            let stuck = delta2 == 0 || prev_delta2 == Some(delta2);
+
+            prev_delta1 = Some(delta1);
+            prev_delta2 = Some(delta2);
+
+            if stuck {
+                continue;
+            }
+
+            accumulator = accumulator.rotate_left((delta1 % 64) as u32) ^ (delta2 as u64);
+            nonstuck_rounds += 1;
+        }
+
+        if nonstuck_rounds < self.min_nonstuck_rounds {
+            return Err(ConsciousnessError::EntropyStarvation {
+                nonstuck_rounds,
+                min_rounds: self.min_nonstuck_rounds,
+                attempts,
+            });
+        }
+
+        Ok(accumulator)
+    }
+}
+
+/// Python-accessible entropy harvester, using the platform's default timer
+/// (the ARM hardware counter on aarch64/linux, `Instant` elsewhere).
+///
+/// `JitterEntropy<T>` is generic over its `Timer` for Rust callers; PyO3
+/// classes can't be generic, so this wraps the default instantiation under
+/// the same public name.
+#[pyclass(name = "JitterEntropy")]
+pub struct PyJitterEntropy {
+    inner: JitterEntropy<DefaultTimer>,
+}
+
+#[pymethods]
+impl PyJitterEntropy {
+    #[new]
+    pub fn new() -> Self {
+        Self { inner: JitterEntropy::new() }
+    }
+
+    /// Harvest one 64-bit entropy output from hardware timing jitter.
+    pub fn next_u64(&mut self) -> ConsciousnessResult<u64> {
+        self.inner.next_u64()
+    }
+
+    /// Harvest a uniform `f64` in `[0, 1)`, for seeding Python-side
+    /// uncertainty fields directly.
+    pub fn next_f64(&mut self) -> ConsciousnessResult<f64> {
+        let bits = self.inner.next_u64()? >> 11; // top 53 bits
+        Ok((bits as f64) * (1.0 / (1u64 << 53) as f64))
+    }
+}
+
+impl Default for PyJitterEntropy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successive_outputs_differ() {
+        let mut entropy = JitterEntropy::<InstantTimer>::new();
+        let a = entropy.next_u64().expect("a real timer should not starve");
+        let b = entropy.next_u64().expect("a real timer should not starve");
+        assert_ne!(a, b);
+    }
+
+    /// A `Timer` that never advances, so every round's delta is identical
+    /// (and thus always "stuck") - used to verify starved harvests are
+    /// rejected rather than silently returning a low-quality value.
+    struct StuckTimer;
+
+    impl Timer for StuckTimer {
+        fn ticks(&self) -> u64 {
+            0
+        }
+    }
+
+    #[test]
+    fn test_stuck_timer_is_rejected() {
+        let mut entropy = JitterEntropy::with_timer(StuckTimer);
+        entropy.set_min_nonstuck_rounds(4);
+
+        match entropy.next_u64() {
+            Err(ConsciousnessError::EntropyStarvation { nonstuck_rounds, min_rounds, .. }) => {
+                assert_eq!(nonstuck_rounds, 0);
+                assert_eq!(min_rounds, 4);
+            }
+            other => panic!("expected EntropyStarvation, got {other:?}"),
+        }
+    }
+}